@@ -0,0 +1,124 @@
+//! Async GPIO edge-change notifications, built on `gpioSetAlertFuncEx`.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::task::{Context, Poll};
+
+use futures::task::AtomicWaker;
+
+use super::PiGpio;
+use super::error;
+
+// BCM GPIO numbers on all Pi models fit in 0..54.
+const PIN_COUNT: usize = 54;
+const NO_EVENT: u64 = u64::MAX;
+
+fn pack(level: bool, tick: u32) -> u64 {
+    ((level as u64) << 32) | u64::from(tick)
+}
+
+fn unpack(packed: u64) -> EdgeEvent {
+    EdgeEvent {
+        level: (packed >> 32) & 1 != 0,
+        tick: packed as u32,
+    }
+}
+
+// One writer (the pigpio alert thread) and one reader (whichever task last polled) per slot, so
+// plain atomics are enough; no mutex needed.
+static SLOTS: [AtomicU64; PIN_COUNT] = [AtomicU64::new(NO_EVENT); PIN_COUNT];
+static WAKERS: OnceLock<[AtomicWaker; PIN_COUNT]> = OnceLock::new();
+// Guards against two live EdgeStreams for the same pin stepping on each other's registration
+// and Drop.
+static WATCHED: [AtomicBool; PIN_COUNT] = [AtomicBool::new(false); PIN_COUNT];
+
+fn wakers() -> &'static [AtomicWaker; PIN_COUNT] {
+    WAKERS.get_or_init(|| std::array::from_fn(|_| AtomicWaker::new()))
+}
+
+/// A level change reported by pigpio.
+#[derive(Copy, Clone, Debug)]
+pub struct EdgeEvent {
+    /// The new level of the pin.
+    pub level: bool,
+    /// The pigpio tick (a free-running, wrapping microsecond counter) at which the change was
+    /// seen.
+    pub tick: u32,
+}
+
+extern "C" fn alert_trampoline(gpio: i32, level: i32, tick: u32, _user_data: *mut std::os::raw::c_void) {
+    if let Some(slot) = SLOTS.get(gpio as usize) {
+        slot.store(pack(level != 0, tick), Ordering::Release);
+        wakers()[gpio as usize].wake();
+    }
+}
+
+impl PiGpio {
+    /// Starts watching `pin` for level changes.
+    ///
+    /// The returned stream yields an [`EdgeEvent`] each time pigpio reports a change, so callers
+    /// can `await` button presses or sensor interrupts instead of polling the pin in a loop.
+    /// Only the most recent event is retained: events that arrive faster than the stream is
+    /// polled are coalesced into the latest one.
+    ///
+    /// Only one [`EdgeStream`] can watch a given pin at a time; a second call for the same pin
+    /// fails with [`error::BadAlert::AlreadyWatched`] until the first stream is dropped.
+    pub fn watch_pin<'a>(&'a self, pin: u8) -> Result<EdgeStream<'a>, error::BadAlert> {
+        if pin as usize >= PIN_COUNT {
+            return Err(error::BadAlert::InvalidPin);
+        }
+
+        if WATCHED[pin as usize].compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire).is_err() {
+            return Err(error::BadAlert::AlreadyWatched);
+        }
+
+        // Clear any event left behind by a previous watcher on this pin; pigpio hasn't reported
+        // anything since this one started.
+        SLOTS[pin as usize].store(NO_EVENT, Ordering::Release);
+
+        let result = unsafe {
+            ::pigpio_sys::gpioSetAlertFuncEx(pin.into(), Some(alert_trampoline), std::ptr::null_mut())
+        };
+        if let Err(e) = error::BadAlert::from_return_code(result) {
+            WATCHED[pin as usize].store(false, Ordering::Release);
+            return Err(e);
+        }
+
+        Ok(EdgeStream {
+            pigpio: Default::default(),
+            pin,
+        })
+    }
+}
+
+/// A stream of [`EdgeEvent`]s for a single pin, created by [`PiGpio::watch_pin`].
+pub struct EdgeStream<'a> {
+    pigpio: std::marker::PhantomData<&'a PiGpio>,
+    pin: u8,
+}
+
+impl<'a> futures::stream::Stream for EdgeStream<'a> {
+    type Item = EdgeEvent;
+
+    fn poll_next(self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let pin = self.pin as usize;
+        // Register before checking the slot so an event delivered between the check and the
+        // registration still wakes us up.
+        wakers()[pin].register(cx.waker());
+
+        match SLOTS[pin].swap(NO_EVENT, Ordering::Acquire) {
+            NO_EVENT => Poll::Pending,
+            packed => Poll::Ready(Some(unpack(packed))),
+        }
+    }
+}
+
+impl<'a> Drop for EdgeStream<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            ::pigpio_sys::gpioSetAlertFuncEx(self.pin.into(), None, std::ptr::null_mut());
+        }
+        SLOTS[self.pin as usize].store(NO_EVENT, Ordering::Release);
+        WATCHED[self.pin as usize].store(false, Ordering::Release);
+    }
+}
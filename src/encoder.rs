@@ -0,0 +1,175 @@
+//! Quadrature (A/B) rotary encoder decoding, built on the alert subsystem.
+
+use std::os::raw::c_void;
+use std::sync::atomic::{AtomicI64, AtomicU8, Ordering};
+use std::sync::Arc;
+
+use super::PiGpio;
+use super::error;
+
+// Indexed by `(old_state << 2) | new_state`, where each state is `(a << 1) | b`. Impossible
+// double transitions (both pins appearing to flip at once) are glitches and count as 0.
+const TRANSITION_TABLE: [i8; 16] = [
+    0, -1, 1, 0,
+    1, 0, 0, -1,
+    -1, 0, 0, 1,
+    0, 1, -1, 0,
+];
+
+struct EncoderState {
+    a_pin: u8,
+    b_pin: u8,
+    ab: AtomicU8,
+    position: AtomicI64,
+}
+
+extern "C" fn encoder_trampoline(gpio: i32, level: i32, _tick: u32, user_data: *mut c_void) {
+    let state = unsafe { &*(user_data as *const EncoderState) };
+
+    let old = state.ab.load(Ordering::Relaxed);
+    let bit = level != 0;
+    let new = if gpio == i32::from(state.a_pin) {
+        (old & 0b01) | ((bit as u8) << 1)
+    } else if gpio == i32::from(state.b_pin) {
+        (old & 0b10) | (bit as u8)
+    } else {
+        return;
+    };
+
+    state.ab.store(new, Ordering::Relaxed);
+    state.position.fetch_add(i64::from(TRANSITION_TABLE[((old << 2) | new) as usize]), Ordering::Relaxed);
+}
+
+impl PiGpio {
+    /// Watches `pin_a`/`pin_b` as a quadrature-encoded incremental rotary encoder.
+    pub fn rotary_encoder<'a>(&'a self, pin_a: u8, pin_b: u8) -> Result<RotaryEncoder<'a>, error::BadAlert> {
+        let state = Arc::new(EncoderState {
+            a_pin: pin_a,
+            b_pin: pin_b,
+            ab: AtomicU8::new(0),
+            position: AtomicI64::new(0),
+        });
+
+        // One Arc clone is handed, as a raw pointer, to each alert registration; Drop below
+        // reclaims both.
+        let user_data_a = Arc::into_raw(state.clone()) as *mut c_void;
+        let user_data_b = Arc::into_raw(state.clone()) as *mut c_void;
+
+        let result = unsafe {
+            ::pigpio_sys::gpioSetAlertFuncEx(pin_a.into(), Some(encoder_trampoline), user_data_a)
+        };
+        if let Err(e) = error::BadAlert::from_return_code(result) {
+            unsafe { drop(Arc::from_raw(user_data_a as *const EncoderState)) };
+            unsafe { drop(Arc::from_raw(user_data_b as *const EncoderState)) };
+            return Err(e);
+        }
+
+        let result = unsafe {
+            ::pigpio_sys::gpioSetAlertFuncEx(pin_b.into(), Some(encoder_trampoline), user_data_b)
+        };
+        if let Err(e) = error::BadAlert::from_return_code(result) {
+            unsafe {
+                ::pigpio_sys::gpioSetAlertFuncEx(pin_a.into(), None, std::ptr::null_mut());
+            }
+            unsafe { drop(Arc::from_raw(user_data_a as *const EncoderState)) };
+            unsafe { drop(Arc::from_raw(user_data_b as *const EncoderState)) };
+            return Err(e);
+        }
+
+        Ok(RotaryEncoder {
+            pigpio: Default::default(),
+            state,
+        })
+    }
+}
+
+/// A two-channel incremental rotary encoder, decoded in software from the raw A/B edges.
+///
+/// Created by [`PiGpio::rotary_encoder`].
+pub struct RotaryEncoder<'a> {
+    pigpio: std::marker::PhantomData<&'a PiGpio>,
+    state: Arc<EncoderState>,
+}
+
+impl<'a> RotaryEncoder<'a> {
+    /// Returns the current position, relative to where the encoder started (or was last
+    /// [`reset`](RotaryEncoder::reset)).
+    pub fn position(&self) -> i64 {
+        self.state.position.load(Ordering::Relaxed)
+    }
+
+    /// Resets the position counter to 0.
+    pub fn reset(&self) {
+        self.state.position.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<'a> Drop for RotaryEncoder<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            ::pigpio_sys::gpioSetAlertFuncEx(self.state.a_pin.into(), None, std::ptr::null_mut());
+            ::pigpio_sys::gpioSetAlertFuncEx(self.state.b_pin.into(), None, std::ptr::null_mut());
+        }
+        // Reclaim the two Arc clones handed to pigpio as userdata pointers in `rotary_encoder`.
+        unsafe {
+            drop(Arc::from_raw(Arc::as_ptr(&self.state)));
+            drop(Arc::from_raw(Arc::as_ptr(&self.state)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transition_table_is_zero_for_no_change_and_for_glitches() {
+        for old in 0u8..4 {
+            for new in 0u8..4 {
+                let delta = TRANSITION_TABLE[((old << 2) | new) as usize];
+                let bits_changed = (old ^ new).count_ones();
+                match bits_changed {
+                    0 | 2 => assert_eq!(delta, 0, "old={old:02b} new={new:02b}"),
+                    1 => assert_ne!(delta, 0, "old={old:02b} new={new:02b}"),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    fn feed(state: &EncoderState, gpio: u8, level: bool) {
+        encoder_trampoline(gpio.into(), level as i32, 0, state as *const EncoderState as *mut c_void);
+    }
+
+    #[test]
+    fn trampoline_accumulates_a_full_forward_rotation() {
+        let state = EncoderState {
+            a_pin: 5,
+            b_pin: 6,
+            ab: AtomicU8::new(0),
+            position: AtomicI64::new(0),
+        };
+
+        // One full quadrature cycle, one edge at a time.
+        feed(&state, 5, true);
+        feed(&state, 6, true);
+        feed(&state, 5, false);
+        feed(&state, 6, false);
+
+        assert_eq!(state.position.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn trampoline_ignores_edges_on_unrelated_pins() {
+        let state = EncoderState {
+            a_pin: 5,
+            b_pin: 6,
+            ab: AtomicU8::new(0),
+            position: AtomicI64::new(0),
+        };
+
+        feed(&state, 17, true);
+
+        assert_eq!(state.position.load(Ordering::Relaxed), 0);
+    }
+}
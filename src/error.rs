@@ -84,3 +84,204 @@ pub enum SetMode {
     InvalidPin,
     InvalidMode,
 }
+
+#[derive(Copy, Clone, Debug)]
+pub enum BadPwm {
+    InvalidPin,
+    InvalidDutyCycle,
+    InvalidRange,
+    InvalidFrequency,
+    NotHardwarePwmPin,
+}
+
+impl BadPwm {
+    pub(crate) fn from_return_code(code: i32) -> Result<(), Self> {
+        use self::BadPwm::*;
+
+        match code {
+            ::pigpio_sys::PI_BAD_USER_GPIO => Err(InvalidPin),
+            ::pigpio_sys::PI_BAD_DUTYCYCLE => Err(InvalidDutyCycle),
+            ::pigpio_sys::PI_BAD_DUTYRANGE => Err(InvalidRange),
+            ::pigpio_sys::PI_BAD_PWM_FREQ => Err(InvalidFrequency),
+            ::pigpio_sys::PI_NOT_HPWM_GPIO => Err(NotHardwarePwmPin),
+            ::pigpio_sys::PI_BAD_GPIO => Err(InvalidPin),
+            ::pigpio_sys::PI_NOT_HPWM_FREQ => Err(InvalidFrequency),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum BadServo {
+    InvalidPin,
+    InvalidPulseWidth,
+}
+
+impl BadServo {
+    pub(crate) fn from_return_code(code: i32) -> Result<(), Self> {
+        use self::BadServo::*;
+
+        match code {
+            ::pigpio_sys::PI_BAD_USER_GPIO => Err(InvalidPin),
+            ::pigpio_sys::PI_BAD_PULSEWIDTH => Err(InvalidPulseWidth),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum BadAlert {
+    InvalidPin,
+    /// The pin already has a live [`crate::EdgeStream`] watching it.
+    AlreadyWatched,
+}
+
+impl BadAlert {
+    pub(crate) fn from_return_code(code: i32) -> Result<(), Self> {
+        match code {
+            ::pigpio_sys::PI_BAD_USER_GPIO => Err(BadAlert::InvalidPin),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum SpiOpen {
+    InvalidChannel,
+    InvalidSpeed,
+    InvalidFlags,
+    NoMoreHandles,
+}
+
+impl SpiOpen {
+    pub(crate) fn from_return_code(code: i32) -> Result<i32, Self> {
+        use self::SpiOpen::*;
+
+        match code {
+            ::pigpio_sys::PI_BAD_SPI_CHANNEL => Err(InvalidChannel),
+            ::pigpio_sys::PI_BAD_SPI_SPEED => Err(InvalidSpeed),
+            ::pigpio_sys::PI_BAD_FLAGS => Err(InvalidFlags),
+            ::pigpio_sys::PI_NO_HANDLE => Err(NoMoreHandles),
+            x => Ok(x),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum SpiTransfer {
+    BadHandle,
+    TransferFailed,
+}
+
+impl SpiTransfer {
+    pub(crate) fn from_return_code(code: i32) -> Result<i32, Self> {
+        use self::SpiTransfer::*;
+
+        match code {
+            ::pigpio_sys::PI_BAD_HANDLE => Err(BadHandle),
+            ::pigpio_sys::PI_SPI_XFER_FAILED => Err(TransferFailed),
+            x => Ok(x),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum I2cOpen {
+    InvalidBus,
+    InvalidAddress,
+    InvalidFlags,
+    NoMoreHandles,
+}
+
+impl I2cOpen {
+    pub(crate) fn from_return_code(code: i32) -> Result<i32, Self> {
+        use self::I2cOpen::*;
+
+        match code {
+            ::pigpio_sys::PI_BAD_I2C_BUS => Err(InvalidBus),
+            ::pigpio_sys::PI_BAD_I2C_ADDR => Err(InvalidAddress),
+            ::pigpio_sys::PI_BAD_FLAGS => Err(InvalidFlags),
+            ::pigpio_sys::PI_NO_HANDLE => Err(NoMoreHandles),
+            x => Ok(x),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum I2cTransfer {
+    BadHandle,
+    ReadFailed,
+    WriteFailed,
+    /// A block transfer was attempted with more than 32 bytes, the SMBus block data limit.
+    TooManyBytes,
+}
+
+impl I2cTransfer {
+    pub(crate) fn from_read_code(code: i32) -> Result<i32, Self> {
+        match code {
+            ::pigpio_sys::PI_BAD_HANDLE => Err(I2cTransfer::BadHandle),
+            ::pigpio_sys::PI_I2C_READ_FAILED => Err(I2cTransfer::ReadFailed),
+            x => Ok(x),
+        }
+    }
+
+    pub(crate) fn from_write_code(code: i32) -> Result<(), Self> {
+        match code {
+            ::pigpio_sys::PI_BAD_HANDLE => Err(I2cTransfer::BadHandle),
+            ::pigpio_sys::PI_I2C_WRITE_FAILED => Err(I2cTransfer::WriteFailed),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum WaveChain {
+    TooMuchNesting,
+    InvalidLoopCount,
+    LoopWithoutRepeat,
+    BadLoopCounter,
+    InvalidWaveId,
+    /// `WaveCmd::EndLoop` appeared without a matching `WaveCmd::Loop`, or a `WaveCmd::Loop` was
+    /// never closed by a `WaveCmd::EndLoop`.
+    UnbalancedLoop,
+}
+
+impl WaveChain {
+    pub(crate) fn from_return_code(code: i32) -> Result<(), Self> {
+        use self::WaveChain::*;
+
+        match code {
+            ::pigpio_sys::PI_CHAIN_NESTING => Err(TooMuchNesting),
+            ::pigpio_sys::PI_CHAIN_LOOP_CNT => Err(InvalidLoopCount),
+            ::pigpio_sys::PI_BAD_CHAIN_LOOP => Err(LoopWithoutRepeat),
+            ::pigpio_sys::PI_CHAIN_COUNTER => Err(BadLoopCounter),
+            ::pigpio_sys::PI_BAD_WAVE_ID => Err(InvalidWaveId),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+#[derive(Copy, Clone, Debug)]
+pub enum PinError {
+    InvalidPin,
+    InvalidLevel,
+}
+
+#[cfg(feature = "embedded-hal")]
+impl PinError {
+    pub(crate) fn from_return_code(code: i32) -> Result<i32, Self> {
+        match code {
+            ::pigpio_sys::PI_BAD_GPIO => Err(PinError::InvalidPin),
+            ::pigpio_sys::PI_BAD_LEVEL => Err(PinError::InvalidLevel),
+            x => Ok(x),
+        }
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl ::embedded_hal::digital::Error for PinError {
+    fn kind(&self) -> ::embedded_hal::digital::ErrorKind {
+        ::embedded_hal::digital::ErrorKind::Other
+    }
+}
@@ -0,0 +1,77 @@
+//! I2C bus handles with register read/write helpers.
+
+use super::PiGpio;
+use super::error;
+
+impl PiGpio {
+    /// Opens an I2C device at `addr` on the given bus.
+    ///
+    /// The returned handle closes the device when dropped.
+    pub fn i2c_open<'a>(&'a self, bus: u8, addr: u8) -> Result<I2c<'a>, error::I2cOpen> {
+        let result = unsafe { ::pigpio_sys::i2cOpen(bus.into(), addr.into(), 0) };
+        let handle = error::I2cOpen::from_return_code(result)?;
+
+        Ok(I2c {
+            pigpio: Default::default(),
+            handle,
+        })
+    }
+}
+
+/// An open I2C device, created by [`PiGpio::i2c_open`]; closes the device on `Drop`.
+pub struct I2c<'a> {
+    pigpio: std::marker::PhantomData<&'a PiGpio>,
+    handle: i32,
+}
+
+impl<'a> I2c<'a> {
+    /// Reads a single byte from the given register.
+    pub fn read_byte_reg(&mut self, reg: u8) -> Result<u8, error::I2cTransfer> {
+        let result = unsafe { ::pigpio_sys::i2cReadByteData(self.handle, reg.into()) };
+        error::I2cTransfer::from_read_code(result).map(|v| v as u8)
+    }
+
+    /// Writes a single byte to the given register.
+    pub fn write_byte_reg(&mut self, reg: u8, val: u8) -> Result<(), error::I2cTransfer> {
+        let result = unsafe { ::pigpio_sys::i2cWriteByteData(self.handle, reg.into(), val.into()) };
+        error::I2cTransfer::from_write_code(result)
+    }
+
+    /// Reads a block of up to 32 bytes starting at the given register into `buf`.
+    ///
+    /// `buf` longer than 32 bytes, the SMBus block data limit, is rejected with
+    /// [`error::I2cTransfer::TooManyBytes`] before it reaches pigpio.
+    pub fn read_block_reg(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), error::I2cTransfer> {
+        if buf.len() > 32 {
+            return Err(error::I2cTransfer::TooManyBytes);
+        }
+
+        let result = unsafe {
+            ::pigpio_sys::i2cReadI2CBlockData(self.handle, reg.into(), buf.as_mut_ptr() as *mut _, buf.len() as u32)
+        };
+        error::I2cTransfer::from_read_code(result).map(|_| ())
+    }
+
+    /// Writes a block of up to 32 bytes from `buf` starting at the given register.
+    ///
+    /// `buf` longer than 32 bytes, the SMBus block data limit, is rejected with
+    /// [`error::I2cTransfer::TooManyBytes`] before it reaches pigpio.
+    pub fn write_block_reg(&mut self, reg: u8, buf: &[u8]) -> Result<(), error::I2cTransfer> {
+        if buf.len() > 32 {
+            return Err(error::I2cTransfer::TooManyBytes);
+        }
+
+        let result = unsafe {
+            ::pigpio_sys::i2cWriteI2CBlockData(self.handle, reg.into(), buf.as_ptr() as *mut _, buf.len() as u32)
+        };
+        error::I2cTransfer::from_write_code(result)
+    }
+}
+
+impl<'a> Drop for I2c<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            ::pigpio_sys::i2cClose(self.handle);
+        }
+    }
+}
@@ -4,8 +4,32 @@
 //! Feel free to help by adding more.
 
 extern crate pigpio_sys;
+extern crate futures;
+#[cfg(feature = "embedded-hal")]
+extern crate embedded_hal;
 
 pub mod error;
+mod pwm;
+mod servo;
+mod alert;
+mod encoder;
+mod spi;
+mod i2c;
+mod serial;
+mod wave_chain;
+#[cfg(feature = "embedded-hal")]
+mod pin;
+
+pub use pwm::Pwm;
+pub use servo::Servo;
+pub use alert::{EdgeEvent, EdgeStream};
+pub use encoder::RotaryEncoder;
+pub use spi::Spi;
+pub use i2c::I2c;
+pub use serial::{DataBits, StopBits, SerialConfig};
+pub use wave_chain::WaveCmd;
+#[cfg(feature = "embedded-hal")]
+pub use pin::Pin;
 
 /// Existence of this struct proves that `pigpio` library was initialized and gives access to its
 /// functions.
@@ -103,6 +127,14 @@ impl PiGpio {
         }
     }
 
+    /// Adds serial data to the waveform, using a typed [`SerialConfig`] instead of raw
+    /// `data_bits`/`stop_bits` integers.
+    ///
+    /// `offset` signifies the number of microseconds this waveform starts after.
+    pub fn wave_add_serial_cfg(&self, pin: u8, cfg: &SerialConfig, offset: u32, data: &mut [u8]) -> Result<(), error::BadSerial> {
+        self.wave_add_serial(pin, cfg.baud, cfg.raw_data_bits(), cfg.raw_stop_bits(), offset, data)
+    }
+
     /// Creates waveform from added data.
     pub fn wave_create<'a>(&'a self) -> Result<Wave<'a>, error::WaveCreate> {
         let result = unsafe { pigpio_sys::gpioWaveCreate() };
@@ -143,6 +175,10 @@ impl<'a> Wave<'a> {
         let result = unsafe { pigpio_sys::gpioWaveTxSend(self.id, mode.sync()) };
         error::WaveSend::from_return_code(result)
     }
+
+    pub(crate) fn id(&self) -> u32 {
+        self.id
+    }
 }
 
 impl<'a> Drop for Wave<'a> {
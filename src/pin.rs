@@ -0,0 +1,74 @@
+//! `embedded-hal` digital I/O compatibility, enabled by the `embedded-hal` feature.
+
+use super::{PiGpio, PinMode};
+use super::error;
+
+impl PiGpio {
+    /// Configures `pin` as a push-pull output and returns a handle implementing the
+    /// `embedded-hal` digital traits.
+    pub fn output_pin<'a>(&'a self, pin: u8) -> Result<Pin<'a>, error::SetMode> {
+        self.set_mode(pin, PinMode::Output)?;
+        Ok(Pin { pigpio: Default::default(), pin })
+    }
+
+    /// Configures `pin` as an input and returns a handle implementing the `embedded-hal` digital
+    /// traits.
+    pub fn input_pin<'a>(&'a self, pin: u8) -> Result<Pin<'a>, error::SetMode> {
+        self.set_mode(pin, PinMode::Input)?;
+        Ok(Pin { pigpio: Default::default(), pin })
+    }
+}
+
+/// A GPIO pin handle implementing the `embedded-hal` `OutputPin`, `StatefulOutputPin` and
+/// `InputPin` traits, so drivers written against `embedded-hal` work on top of `pigpio`. Created
+/// by [`PiGpio::output_pin`] or [`PiGpio::input_pin`].
+pub struct Pin<'a> {
+    pigpio: std::marker::PhantomData<&'a PiGpio>,
+    pin: u8,
+}
+
+impl<'a> Pin<'a> {
+    fn write(&mut self, level: u32) -> Result<(), error::PinError> {
+        let result = unsafe { ::pigpio_sys::gpioWrite(self.pin.into(), level) };
+        error::PinError::from_return_code(result).map(|_| ())
+    }
+
+    fn read(&self) -> Result<bool, error::PinError> {
+        let result = unsafe { ::pigpio_sys::gpioRead(self.pin.into()) };
+        error::PinError::from_return_code(result).map(|level| level != 0)
+    }
+}
+
+impl<'a> ::embedded_hal::digital::ErrorType for Pin<'a> {
+    type Error = error::PinError;
+}
+
+impl<'a> ::embedded_hal::digital::OutputPin for Pin<'a> {
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.write(1)
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.write(0)
+    }
+}
+
+impl<'a> ::embedded_hal::digital::StatefulOutputPin for Pin<'a> {
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        self.read()
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        self.read().map(|level| !level)
+    }
+}
+
+impl<'a> ::embedded_hal::digital::InputPin for Pin<'a> {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        self.read()
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        self.read().map(|level| !level)
+    }
+}
@@ -0,0 +1,68 @@
+//! Software and hardware PWM.
+
+use super::PiGpio;
+use super::error;
+
+impl PiGpio {
+    /// Obtains a software PWM handle for the given pin.
+    ///
+    /// The handle starts out with whatever range/frequency/duty cycle pigpio currently has
+    /// configured for the pin; use [`Pwm::set_range`], [`Pwm::set_frequency`] and
+    /// [`Pwm::set_duty`] to configure it.
+    pub fn pwm_pin<'a>(&'a self, pin: u8) -> Pwm<'a> {
+        Pwm {
+            pigpio: Default::default(),
+            pin,
+        }
+    }
+
+    /// Starts hardware PWM on `pin` at `frequency` Hz with the given `duty_cycle`.
+    ///
+    /// `duty_cycle` is in the range 0 (off) to 1000000 (fully on). Only GPIO 12, 13, 18 and 19
+    /// carry a hardware PWM channel; any other pin is rejected locally with
+    /// [`error::BadPwm::NotHardwarePwmPin`] rather than round-tripping through pigpio.
+    pub fn hardware_pwm(&self, pin: u8, frequency: u32, duty_cycle: u32) -> Result<(), error::BadPwm> {
+        if !matches!(pin, 12 | 13 | 18 | 19) {
+            return Err(error::BadPwm::NotHardwarePwmPin);
+        }
+
+        let result = unsafe { ::pigpio_sys::gpioHardwarePWM(pin.into(), frequency, duty_cycle) };
+        error::BadPwm::from_return_code(result)
+    }
+}
+
+/// A software PWM output on a single pin, created by [`PiGpio::pwm_pin`].
+pub struct Pwm<'a> {
+    pigpio: std::marker::PhantomData<&'a PiGpio>,
+    pin: u8,
+}
+
+impl<'a> Pwm<'a> {
+    /// Sets the duty cycle, in the range `0..=range` (`range` defaults to 255 unless changed
+    /// with [`Pwm::set_range`]).
+    pub fn set_duty(&self, duty: u32) -> Result<(), error::BadPwm> {
+        let result = unsafe { ::pigpio_sys::gpioPWM(self.pin.into(), duty) };
+        error::BadPwm::from_return_code(result)
+    }
+
+    /// Sets the range against which the duty cycle passed to [`Pwm::set_duty`] is measured.
+    pub fn set_range(&self, range: u32) -> Result<(), error::BadPwm> {
+        let result = unsafe { ::pigpio_sys::gpioSetPWMrange(self.pin.into(), range) };
+        error::BadPwm::from_return_code(result)
+    }
+
+    /// Sets the PWM frequency, in Hz.
+    ///
+    /// pigpio picks the closest frequency it can actually generate; it doesn't report which one
+    /// was chosen.
+    pub fn set_frequency(&self, frequency: u32) -> Result<(), error::BadPwm> {
+        let result = unsafe { ::pigpio_sys::gpioSetPWMfrequency(self.pin.into(), frequency) };
+        error::BadPwm::from_return_code(result)
+    }
+
+    /// Returns the currently configured duty cycle.
+    pub fn duty(&self) -> Result<u32, error::BadPwm> {
+        let result = unsafe { ::pigpio_sys::gpioGetPWMdutycycle(self.pin.into()) };
+        error::BadPwm::from_return_code(result).map(|()| result as u32)
+    }
+}
@@ -0,0 +1,53 @@
+//! Typed framing configuration for `wave_add_serial`.
+
+/// Number of data bits per serial character.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DataBits {
+    Five = 5,
+    Six = 6,
+    Seven = 7,
+    Eight = 8,
+}
+
+impl DataBits {
+    fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Number of stop bits per serial character.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum StopBits {
+    /// One stop bit.
+    One,
+    /// Two stop bits.
+    Two,
+}
+
+impl StopBits {
+    // pigpio counts stop bits in halves, so 1 stop bit is encoded as 2 and 2 stop bits as 4.
+    fn as_u8(self) -> u8 {
+        match self {
+            StopBits::One => 2,
+            StopBits::Two => 4,
+        }
+    }
+}
+
+/// Framing configuration for a `wave_add_serial_cfg` call.
+#[derive(Copy, Clone, Debug)]
+pub struct SerialConfig {
+    pub baud: u32,
+    pub data_bits: DataBits,
+    pub stop_bits: StopBits,
+}
+
+impl SerialConfig {
+    pub(crate) fn raw_data_bits(&self) -> u8 {
+        self.data_bits.as_u8()
+    }
+
+    pub(crate) fn raw_stop_bits(&self) -> u8 {
+        self.stop_bits.as_u8()
+    }
+}
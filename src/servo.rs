@@ -0,0 +1,42 @@
+//! Hobby servo control, built on top of `gpioServo`.
+
+use super::PiGpio;
+use super::error;
+
+impl PiGpio {
+    /// Obtains a servo handle for the given pin.
+    pub fn servo_pin<'a>(&'a self, pin: u8) -> Servo<'a> {
+        Servo {
+            pigpio: Default::default(),
+            pin,
+        }
+    }
+}
+
+/// A hobby servo connected to a single pin, created by [`PiGpio::servo_pin`].
+pub struct Servo<'a> {
+    pigpio: std::marker::PhantomData<&'a PiGpio>,
+    pin: u8,
+}
+
+impl<'a> Servo<'a> {
+    /// Sets the servo pulse width, in microseconds.
+    ///
+    /// Valid values are `0` (switches pulses off) or `500..=2500`. Anything else is rejected
+    /// with [`error::BadServo::InvalidPulseWidth`].
+    pub fn set_pulsewidth(&self, micros: u16) -> Result<(), error::BadServo> {
+        if micros != 0 && (micros < 500 || micros > 2500) {
+            return Err(error::BadServo::InvalidPulseWidth);
+        }
+
+        let result = unsafe { ::pigpio_sys::gpioServo(self.pin.into(), micros.into()) };
+        error::BadServo::from_return_code(result)
+    }
+
+    /// Returns the currently configured pulse width, in microseconds, or `0` if servo pulses
+    /// are currently switched off on this pin.
+    pub fn get_pulsewidth(&self) -> Result<u16, error::BadServo> {
+        let result = unsafe { ::pigpio_sys::gpioGetServoPulsewidth(self.pin.into()) };
+        error::BadServo::from_return_code(result).map(|()| result as u16)
+    }
+}
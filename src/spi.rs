@@ -0,0 +1,58 @@
+//! Hardware SPI master, wrapping `spiOpen`/`spiXfer`/`spiRead`/`spiWrite`.
+
+use super::PiGpio;
+use super::error;
+
+impl PiGpio {
+    /// Opens an SPI channel at the given baud rate.
+    ///
+    /// `flags` are the raw pigpio `spiOpen` flags (mode, chip-select polarity, auxiliary
+    /// interface selection, ...); see the pigpio documentation for their meaning. The returned
+    /// handle closes the channel when dropped.
+    pub fn spi_open<'a>(&'a self, channel: u8, baud: u32, flags: u32) -> Result<Spi<'a>, error::SpiOpen> {
+        let result = unsafe { ::pigpio_sys::spiOpen(channel.into(), baud, flags) };
+        let handle = error::SpiOpen::from_return_code(result)?;
+
+        Ok(Spi {
+            pigpio: Default::default(),
+            handle,
+        })
+    }
+}
+
+/// An open SPI channel, created by [`PiGpio::spi_open`]; closes the channel on `Drop`.
+pub struct Spi<'a> {
+    pigpio: std::marker::PhantomData<&'a PiGpio>,
+    handle: i32,
+}
+
+impl<'a> Spi<'a> {
+    /// Performs a full-duplex transfer: the contents of `buf` are sent, and the bytes received
+    /// over the same period overwrite `buf` in place.
+    pub fn transfer(&mut self, buf: &mut [u8]) -> Result<(), error::SpiTransfer> {
+        let result = unsafe {
+            ::pigpio_sys::spiXfer(self.handle, buf.as_mut_ptr() as *mut _, buf.as_mut_ptr() as *mut _, buf.len() as u32)
+        };
+        error::SpiTransfer::from_return_code(result).map(|_| ())
+    }
+
+    /// Reads `buf.len()` bytes, ignoring whatever is clocked out on MOSI while doing so.
+    pub fn read(&mut self, buf: &mut [u8]) -> Result<(), error::SpiTransfer> {
+        let result = unsafe { ::pigpio_sys::spiRead(self.handle, buf.as_mut_ptr() as *mut _, buf.len() as u32) };
+        error::SpiTransfer::from_return_code(result).map(|_| ())
+    }
+
+    /// Writes `buf`, ignoring whatever is clocked in on MISO while doing so.
+    pub fn write(&mut self, buf: &[u8]) -> Result<(), error::SpiTransfer> {
+        let result = unsafe { ::pigpio_sys::spiWrite(self.handle, buf.as_ptr() as *mut _, buf.len() as u32) };
+        error::SpiTransfer::from_return_code(result).map(|_| ())
+    }
+}
+
+impl<'a> Drop for Spi<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            ::pigpio_sys::spiClose(self.handle);
+        }
+    }
+}
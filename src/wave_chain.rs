@@ -0,0 +1,123 @@
+//! Waveform chaining (`gpioWaveChain`): sequencing created waveforms with repeats, nested
+//! loops and delays.
+
+use super::{PiGpio, Wave};
+use super::error;
+
+/// A single step in a [`PiGpio::wave_chain`] sequence.
+pub enum WaveCmd<'a, 'w> {
+    /// Transmits a previously created waveform.
+    Wave(&'w Wave<'a>),
+    /// Starts a loop that repeats the commands up to the matching [`WaveCmd::EndLoop`] `count`
+    /// times, or forever if `count` is 0.
+    Loop { count: u16 },
+    /// Closes the most recently opened [`WaveCmd::Loop`].
+    EndLoop,
+    /// Delays the chain by the given number of microseconds.
+    DelayMicros(u16),
+}
+
+/// Casts a wave id to the single byte `gpioWaveChain` expects, rejecting ids that would collide
+/// with the `255` command-escape byte.
+fn wave_id_byte(id: u32) -> Result<u8, error::WaveChain> {
+    if id >= 255 {
+        return Err(error::WaveChain::InvalidWaveId);
+    }
+    Ok(id as u8)
+}
+
+/// Encodes `cmds` into the byte stream `gpioWaveChain` expects.
+fn encode_chain(cmds: &[WaveCmd]) -> Result<Vec<u8>, error::WaveChain> {
+    let mut buf = Vec::new();
+    let mut open_loops = Vec::new();
+
+    for cmd in cmds {
+        match *cmd {
+            WaveCmd::Wave(wave) => buf.push(wave_id_byte(wave.id())?),
+            WaveCmd::Loop { count } => {
+                open_loops.push(count);
+                buf.extend_from_slice(&[255, 0]);
+            }
+            WaveCmd::EndLoop => {
+                let count = open_loops.pop().ok_or(error::WaveChain::UnbalancedLoop)?;
+                if count == 0 {
+                    buf.extend_from_slice(&[255, 3]);
+                } else {
+                    let [lo, hi] = count.to_le_bytes();
+                    buf.extend_from_slice(&[255, 1, lo, hi]);
+                }
+            }
+            WaveCmd::DelayMicros(micros) => {
+                let [lo, hi] = micros.to_le_bytes();
+                buf.extend_from_slice(&[255, 2, lo, hi]);
+            }
+        }
+    }
+
+    if !open_loops.is_empty() {
+        return Err(error::WaveChain::UnbalancedLoop);
+    }
+
+    Ok(buf)
+}
+
+impl PiGpio {
+    /// Transmits a sequence of previously created waveforms, with optional loops and delays.
+    ///
+    /// The referenced [`Wave`]s must still be alive; this is enforced by the borrow in
+    /// [`WaveCmd::Wave`].
+    pub fn wave_chain(&self, cmds: &[WaveCmd]) -> Result<(), error::WaveChain> {
+        let mut buf = encode_chain(cmds)?;
+
+        let result = unsafe { ::pigpio_sys::gpioWaveChain(buf.as_mut_ptr(), buf.len() as u32) };
+        error::WaveChain::from_return_code(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wave_id_byte_accepts_in_range_ids() {
+        assert_eq!(wave_id_byte(0).unwrap(), 0);
+        assert_eq!(wave_id_byte(254).unwrap(), 254);
+    }
+
+    #[test]
+    fn wave_id_byte_rejects_the_escape_byte_and_above() {
+        assert!(matches!(wave_id_byte(255), Err(error::WaveChain::InvalidWaveId)));
+        assert!(matches!(wave_id_byte(256), Err(error::WaveChain::InvalidWaveId)));
+    }
+
+    #[test]
+    fn encode_chain_emits_delay_as_escape_sequence() {
+        let buf = encode_chain(&[WaveCmd::DelayMicros(300)]).unwrap();
+        assert_eq!(buf, vec![255, 2, 44, 1]);
+    }
+
+    #[test]
+    fn encode_chain_emits_finite_loop_with_count() {
+        let buf = encode_chain(&[WaveCmd::Loop { count: 3 }, WaveCmd::EndLoop]).unwrap();
+        assert_eq!(buf, vec![255, 0, 255, 1, 3, 0]);
+    }
+
+    #[test]
+    fn encode_chain_emits_infinite_loop_as_forever_escape() {
+        let buf = encode_chain(&[WaveCmd::Loop { count: 0 }, WaveCmd::EndLoop]).unwrap();
+        assert_eq!(buf, vec![255, 0, 255, 3]);
+    }
+
+    #[test]
+    fn encode_chain_rejects_unbalanced_end_loop() {
+        assert!(matches!(encode_chain(&[WaveCmd::EndLoop]), Err(error::WaveChain::UnbalancedLoop)));
+    }
+
+    #[test]
+    fn encode_chain_rejects_loop_left_open() {
+        assert!(matches!(
+            encode_chain(&[WaveCmd::Loop { count: 1 }]),
+            Err(error::WaveChain::UnbalancedLoop)
+        ));
+    }
+}